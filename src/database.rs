@@ -0,0 +1,36 @@
+use {
+    diesel_async::{
+        pooled_connection::{
+            bb8::{
+                Pool,
+                PooledConnection,
+            },
+            AsyncDieselConnectionManager,
+        },
+        AsyncPgConnection,
+    },
+    std::sync::Arc,
+};
+
+pub struct Database {
+    pool: Pool<AsyncPgConnection>,
+}
+
+impl Database {
+    pub async fn new(database_url: &str) -> Arc<Self> {
+        let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(database_url);
+        let pool = Pool::builder()
+            .build(manager)
+            .await
+            .expect("failed to build database pool");
+
+        Arc::new(Self { pool })
+    }
+
+    pub async fn get_connection(&self) -> PooledConnection<'_, AsyncPgConnection> {
+        self.pool
+            .get()
+            .await
+            .expect("failed to get database connection")
+    }
+}
@@ -0,0 +1,83 @@
+use {
+    axum::{
+        http::StatusCode,
+        response::{
+            IntoResponse,
+            Response,
+        },
+        Json,
+    },
+    diesel::result::Error as DieselError,
+    serde_json::json,
+    thiserror::Error,
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("user already exists")]
+    UserAlreadyExists,
+    #[error("record not found")]
+    RecordNotFound,
+    #[error("this account has been blocked")]
+    UserBlocked,
+    #[error("invite link is invalid or has expired")]
+    InviteInvalidOrExpired,
+    #[error("query failed: {0}")]
+    QueryFailed(DieselError),
+    #[error("insert failed: {0}")]
+    InsertFailed(DieselError),
+    #[error("update failed: {0}")]
+    UpdateFailed(DieselError),
+    #[error("delete failed: {0}")]
+    DeleteFailed(DieselError),
+    #[error("invalid file type")]
+    FileTypeInvalid,
+    #[error("failed to create file")]
+    CreateFileFailed,
+    #[error("missing field: {0}")]
+    FieldNotFound(String),
+    #[error("failed to hash password")]
+    PasswordHashFailed,
+    #[error("image exceeds the maximum allowed dimensions or file size")]
+    ImageTooLarge,
+    #[error("failed to decode image")]
+    ImageDecodeFailed,
+    #[error("failed to encode image")]
+    ImageEncodeFailed,
+    #[error(transparent)]
+    Anyhow(#[from] anyhow::Error),
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Error::UserAlreadyExists => StatusCode::CONFLICT,
+            Error::RecordNotFound => StatusCode::NOT_FOUND,
+            Error::UserBlocked => StatusCode::UNAUTHORIZED,
+            Error::FileTypeInvalid
+            | Error::FieldNotFound(_)
+            | Error::ImageTooLarge
+            | Error::InviteInvalidOrExpired => StatusCode::BAD_REQUEST,
+            Error::QueryFailed(_)
+            | Error::InsertFailed(_)
+            | Error::UpdateFailed(_)
+            | Error::DeleteFailed(_)
+            | Error::CreateFileFailed
+            | Error::PasswordHashFailed
+            | Error::ImageDecodeFailed
+            | Error::ImageEncodeFailed
+            | Error::Anyhow(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (
+            status,
+            Json(json!({
+                "status": status.to_string(),
+                "result": { "msg": self.to_string() },
+            })),
+        )
+            .into_response()
+    }
+}
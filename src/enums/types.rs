@@ -0,0 +1,24 @@
+use {
+    serde::Serialize,
+    utoipa::ToSchema,
+};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DataResponse<T> {
+    pub msg: String,
+    pub data: Option<T>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenericResponse<T> {
+    pub status: String,
+    pub result: DataResponse<T>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+}
@@ -0,0 +1,114 @@
+use {
+    crate::enums::errors::Error,
+    image::{
+        imageops::FilterType,
+        DynamicImage,
+        ImageFormat,
+        ImageReader,
+    },
+    std::io::Cursor,
+};
+
+/// Images larger than this on either axis are rejected outright, to keep a single
+/// malformed or decompression-bomb upload from blowing up memory/CPU.
+const MAX_AVATAR_DIMENSION: u32 = 4096;
+/// Raw upload size cap, checked before decoding.
+const MAX_AVATAR_BYTES: usize = 10 * 1024 * 1024;
+const THUMBNAIL_SIZE: u32 = 128;
+
+pub struct ProcessedAvatar {
+    pub full: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+}
+
+/// Decodes, validates and re-encodes an uploaded avatar, producing a normalized full-size
+/// image alongside a fixed `THUMBNAIL_SIZE`x`THUMBNAIL_SIZE` center-cropped thumbnail.
+pub fn process_avatar(data: &[u8]) -> Result<ProcessedAvatar, Error> {
+    if data.len() > MAX_AVATAR_BYTES {
+        return Err(Error::ImageTooLarge);
+    }
+
+    let reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|_| Error::ImageDecodeFailed)?;
+
+    let (width, height) = reader.into_dimensions().map_err(|_| Error::ImageDecodeFailed)?;
+    if width > MAX_AVATAR_DIMENSION || height > MAX_AVATAR_DIMENSION {
+        return Err(Error::ImageTooLarge);
+    }
+
+    let image = image::load_from_memory(data).map_err(|_| Error::ImageDecodeFailed)?;
+
+    let full = encode(&image)?;
+    let thumbnail = encode(&center_crop_square(&image, THUMBNAIL_SIZE))?;
+
+    Ok(ProcessedAvatar { full, thumbnail })
+}
+
+fn center_crop_square(image: &DynamicImage, size: u32) -> DynamicImage {
+    let shortest_side = image.width().min(image.height());
+    let x = (image.width() - shortest_side) / 2;
+    let y = (image.height() - shortest_side) / 2;
+
+    image
+        .crop_imm(x, y, shortest_side, shortest_side)
+        .resize_exact(size, size, FilterType::Lanczos3)
+}
+
+// PNG, not WebP: the `image` crate has shipped WebP as decode-only for long stretches of
+// its history, so encoding here would fail in a way that's easy to miss until runtime.
+fn encode(image: &DynamicImage) -> Result<Vec<u8>, Error> {
+    let mut buf = Cursor::new(Vec::new());
+    image
+        .write_to(&mut buf, ImageFormat::Png)
+        .map_err(|_| Error::ImageEncodeFailed)?;
+
+    Ok(buf.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_png(image: &DynamicImage) -> Vec<u8> {
+        let mut buf = Cursor::new(Vec::new());
+        image.write_to(&mut buf, ImageFormat::Png).unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn rejects_payload_over_the_byte_cap() {
+        let oversized = vec![0u8; MAX_AVATAR_BYTES + 1];
+
+        assert!(matches!(process_avatar(&oversized), Err(Error::ImageTooLarge)));
+    }
+
+    #[test]
+    fn rejects_image_over_the_dimension_cap() {
+        let huge = DynamicImage::new_rgb8(MAX_AVATAR_DIMENSION + 1, 1);
+        let data = encode_png(&huge);
+
+        assert!(matches!(process_avatar(&data), Err(Error::ImageTooLarge)));
+    }
+
+    #[test]
+    fn accepts_a_small_image_and_produces_both_buffers() {
+        let small = DynamicImage::new_rgb8(64, 32);
+        let data = encode_png(&small);
+
+        let processed = process_avatar(&data).unwrap();
+
+        assert!(!processed.full.is_empty());
+        assert!(!processed.thumbnail.is_empty());
+    }
+
+    #[test]
+    fn center_crop_square_produces_the_requested_size() {
+        let image = DynamicImage::new_rgb8(200, 100);
+
+        let cropped = center_crop_square(&image, THUMBNAIL_SIZE);
+
+        assert_eq!(cropped.width(), THUMBNAIL_SIZE);
+        assert_eq!(cropped.height(), THUMBNAIL_SIZE);
+    }
+}
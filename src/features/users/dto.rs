@@ -0,0 +1,74 @@
+use {
+    super::{
+        models::User,
+        public_id::PublicId,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    utoipa::{
+        IntoParams,
+        ToSchema,
+    },
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateUserRequest {
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListUsersQuery {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    pub sort: Option<String>,
+    pub q: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InviteUserRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AcceptInviteRequest {
+    pub token: String,
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateUserRequest {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub avatar: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UserResponse {
+    pub id: PublicId,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+    pub avatar: Option<String>,
+    pub avatar_thumbnail: Option<String>,
+    pub blocked: bool,
+}
+
+impl From<User> for UserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: PublicId::new(user.id),
+            name: user.name,
+            email: user.email,
+            role: user.role,
+            avatar: user.avatar,
+            avatar_thumbnail: user.avatar_thumbnail,
+            blocked: user.blocked,
+        }
+    }
+}
@@ -2,8 +2,12 @@
 
 use {
     super::{
+        avatar::process_avatar,
         dto::{
+            AcceptInviteRequest,
             CreateUserRequest,
+            InviteUserRequest,
+            ListUsersQuery,
             UpdateUserRequest,
             UserResponse,
         },
@@ -11,6 +15,9 @@ use {
             NewUser,
             User,
         },
+        password::hash_password,
+        public_id::PublicId,
+        storage::AvatarStore,
     },
     crate::{
         database::Database,
@@ -19,6 +26,7 @@ use {
             types::{
                 DataResponse,
                 GenericResponse,
+                Paginated,
             },
         },
         schema::users,
@@ -28,6 +36,7 @@ use {
         extract::{
             Multipart,
             Path,
+            Query,
         },
         http::StatusCode,
         response::IntoResponse,
@@ -35,19 +44,20 @@ use {
         Json,
     },
     axum_chat_app::only_role,
+    chrono::{
+        Duration,
+        Utc,
+    },
     diesel::{
         delete,
         insert_into,
+        pg::PgTextExpressionMethods,
         prelude::*,
         update,
     },
     diesel_async::RunQueryDsl,
     regex::Regex,
     std::sync::Arc,
-    tokio::{
-        fs::File,
-        io::AsyncWriteExt,
-    },
     tracing::debug,
     uuid::Uuid,
 };
@@ -71,6 +81,8 @@ pub async fn create_user(
     Extension(sender): Extension<UserResponse>,
     Json(payload): Json<CreateUserRequest>,
 ) -> Result<impl IntoResponse> {
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
     let mut conn = db.get_connection().await;
 
     // Count the number of users with the given email
@@ -92,13 +104,17 @@ pub async fn create_user(
         return Err(Error::UserAlreadyExists);
     }
 
+    let hashed_password = hash_password(&payload.password)?;
+
     insert_into(users::table)
         .values(NewUser {
             name: &payload.username,
             email: &payload.email,
-            password: &payload.password,
+            password: Some(&hashed_password),
             role: &payload.role.unwrap_or("user".to_string()),
             avatar: None,
+            invite_token: None,
+            invite_expires_at: None,
         })
         .execute(&mut conn)
         .await
@@ -119,12 +135,154 @@ pub async fn create_user(
     ))
 }
 
+const INVITE_TTL_DAYS: i64 = 7;
+
+#[utoipa::path(
+    post,
+    context_path = "/api",
+    path = "/users/invite",
+    request_body = InviteUserRequest,
+    responses(
+        (status = 201, description = "Invite created successfully", body = GenericResponse<String>),
+        (status = 409, description = "User already exists"),
+        (status = 500, description = "Internal Server Error"),
+    ),
+    security(("bearerAuth" = [])), // Apply JWT security only here
+    tag = "Users"
+)]
+#[only_role("admin")]
+pub async fn invite_user(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(sender): Extension<UserResponse>,
+    Json(payload): Json<InviteUserRequest>,
+) -> Result<impl IntoResponse> {
+    debug!("invite_user: sender {sender:?}, payload: {payload:?}");
+
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
+    let mut conn = db.get_connection().await;
+
+    let existing_count = users::table
+        .filter(users::email.eq(&payload.email))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .await
+        .map_err(Error::QueryFailed)?;
+
+    if existing_count > 0 {
+        return Err(Error::UserAlreadyExists);
+    }
+
+    let invite_token = Uuid::new_v4().to_string();
+    let invite_expires_at = Utc::now() + Duration::days(INVITE_TTL_DAYS);
+
+    insert_into(users::table)
+        .values(NewUser {
+            name: "",
+            email: &payload.email,
+            password: None,
+            role: "user",
+            avatar: None,
+            invite_token: Some(&invite_token),
+            invite_expires_at: Some(invite_expires_at),
+        })
+        .execute(&mut conn)
+        .await
+        .map_err(|e| {
+            warn!("{}", e.to_string());
+            Error::InsertFailed(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(GenericResponse {
+            status: StatusCode::CREATED.to_string(),
+            result: DataResponse::<String> {
+                msg: "invite sent successfully".into(),
+                data: None,
+            },
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    context_path = "/api",
+    path = "/users/accept-invite",
+    request_body = AcceptInviteRequest,
+    responses(
+        (status = 202, description = "Invite accepted, account activated", body = GenericResponse<String>),
+        (status = 400, description = "Invite invalid or expired"),
+        (status = 500, description = "Internal Server Error"),
+    ),
+    tag = "Users"
+)]
+pub async fn accept_invite(
+    Extension(db): Extension<Arc<Database>>,
+    Json(payload): Json<AcceptInviteRequest>,
+) -> Result<impl IntoResponse> {
+    debug!("accept_invite: username {}", payload.username);
+
+    let mut conn = db.get_connection().await;
+    let mut existed_user: User = users::table
+        .filter(users::invite_token.eq(&payload.token))
+        .select(User::as_select())
+        .first(&mut conn)
+        .await
+        .map_err(|_| Error::InviteInvalidOrExpired)?;
+
+    let expired = existed_user
+        .invite_expires_at
+        .map(|expires_at| expires_at < Utc::now())
+        .unwrap_or(true);
+
+    if expired {
+        return Err(Error::InviteInvalidOrExpired);
+    }
+
+    let username_count = users::table
+        .filter(users::name.eq(&payload.username))
+        .filter(users::id.ne(existed_user.id))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .await
+        .map_err(Error::QueryFailed)?;
+
+    if username_count > 0 {
+        return Err(Error::UserAlreadyExists);
+    }
+
+    let hashed_password = hash_password(&payload.password)?;
+
+    existed_user.name = payload.username;
+    existed_user.password = Some(hashed_password);
+    existed_user.invite_token = None;
+    existed_user.invite_expires_at = None;
+
+    update(users::table.filter(users::id.eq(existed_user.id)))
+        .set(existed_user)
+        .execute(&mut conn)
+        .await
+        .map_err(Error::UpdateFailed)?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(GenericResponse {
+            status: StatusCode::ACCEPTED.to_string(),
+            result: DataResponse::<String> {
+                msg: "account activated successfully".into(),
+                data: None,
+            },
+        }),
+    ))
+}
+
 #[utoipa::path(
     get,
     context_path = "/api",
     path = "/users/{id}",
     params(
-        ("id" = Uuid, description = "User ID")
+        ("id" = PublicId, description = "User ID")
     ),
     operation_id = "get_user_by_id",
     responses(
@@ -138,13 +296,15 @@ pub async fn create_user(
 pub async fn get_user_by_id(
     Extension(db): Extension<Arc<Database>>,
     Extension(sender): Extension<UserResponse>,
-    Path(id): Path<Uuid>,
+    Path(id): Path<PublicId>,
 ) -> Result<impl IntoResponse> {
     debug!("get_user_by_id: sender {:?}, id {id}", sender);
 
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
     let mut conn = db.get_connection().await;
     let user = users::table
-        .find(id)
+        .find(id.into_uuid())
         .select(User::as_select())
         .first::<User>(&mut conn)
         .await
@@ -164,12 +324,24 @@ pub async fn get_user_by_id(
     ))
 }
 
+const DEFAULT_PER_PAGE: i64 = 20;
+const MAX_PER_PAGE: i64 = 100;
+
+/// Escapes `ILIKE`'s wildcard characters so a literal `%`/`_` in a search term isn't
+/// treated as a pattern wildcard (Postgres's default `ILIKE` escape character is `\`).
+fn escape_like_pattern(raw: &str) -> String {
+    raw.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 #[utoipa::path(
     get,
     context_path = "/api",
     path = "/users",
+    params(ListUsersQuery),
     responses(
-        (status = 200, description = "List of users", body = GenericResponse<Vec<UserResponse>>),
+        (status = 200, description = "Paginated list of users", body = GenericResponse<Paginated<UserResponse>>),
         (status = 500, description = "Internal Server Error"),
     ),
     operation_id = "get_all_user",
@@ -180,11 +352,50 @@ pub async fn get_user_by_id(
 pub async fn get_all_user(
     Extension(db): Extension<Arc<Database>>,
     Extension(sender): Extension<UserResponse>,
+    Query(query): Query<ListUsersQuery>,
 ) -> Result<impl IntoResponse> {
-    debug!("get_all_user: sender {sender:?}");
+    debug!("get_all_user: sender {sender:?}, query: {query:?}");
+
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE);
 
     let mut conn = db.get_connection().await;
-    let users = users::table
+
+    let mut count_query = users::table.into_boxed();
+    let mut list_query = users::table.into_boxed();
+
+    if let Some(q) = &query.q {
+        let pattern = format!("%{}%", escape_like_pattern(q));
+        count_query = count_query.filter(
+            users::name
+                .ilike(pattern.clone())
+                .or(users::email.ilike(pattern.clone())),
+        );
+        list_query = list_query.filter(
+            users::name
+                .ilike(pattern.clone())
+                .or(users::email.ilike(pattern)),
+        );
+    }
+
+    let total = count_query
+        .count()
+        .get_result::<i64>(&mut conn)
+        .await
+        .map_err(Error::QueryFailed)?;
+
+    list_query = match query.sort.as_deref() {
+        Some("name") => list_query.order(users::name.asc()),
+        Some("email") => list_query.order(users::email.asc()),
+        Some("created_at") => list_query.order(users::created_at.asc()),
+        _ => list_query.order(users::created_at.desc()),
+    };
+
+    let users = list_query
+        .limit(per_page)
+        .offset((page - 1) * per_page)
         .select(User::as_select())
         .load::<User>(&mut conn)
         .await
@@ -199,7 +410,12 @@ pub async fn get_all_user(
             status: StatusCode::OK.to_string(),
             result: DataResponse {
                 msg: "success".into(),
-                data: Some(users),
+                data: Some(Paginated {
+                    items: users,
+                    total,
+                    page,
+                    per_page,
+                }),
             },
         }),
     ))
@@ -231,9 +447,12 @@ pub async fn update_user(
         avatar,
     } = payload;
 
+    let sender_id = sender.id.into_uuid();
+    ensure_not_blocked(&db, sender_id).await?;
+
     let mut conn = db.get_connection().await;
     let mut existed_user: User = users::table
-        .find(sender.id)
+        .find(sender_id)
         .select(User::as_select())
         .first(&mut conn)
         .await
@@ -249,7 +468,7 @@ pub async fn update_user(
         existed_user.avatar = avatar;
     }
 
-    update(users::table.filter(users::id.eq(sender.id)))
+    update(users::table.filter(users::id.eq(sender_id)))
         .set(existed_user)
         .returning(User::as_returning())
         .get_result(&mut conn)
@@ -273,7 +492,7 @@ pub async fn update_user(
     context_path = "/api",
     path = "/users/{id}",
     params(
-        ("id" = Uuid, Path, description = "User ID")
+        ("id" = PublicId, Path, description = "User ID")
     ),
     responses(
         (status = 204, description = "User deleted successfully", body = GenericResponse<String>),
@@ -286,11 +505,29 @@ pub async fn update_user(
 pub async fn delete_user(
     Extension(db): Extension<Arc<Database>>,
     Extension(sender): Extension<UserResponse>,
-    Path(id): Path<Uuid>,
+    Extension(store): Extension<Arc<dyn AvatarStore>>,
+    Path(id): Path<PublicId>,
 ) -> Result<impl IntoResponse> {
     debug!("delete_user: sender {:?}, id {id}", sender);
 
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
+    let id = id.into_uuid();
     let mut conn = db.get_connection().await;
+    let existed_user = users::table
+        .find(id)
+        .select(User::as_select())
+        .first::<User>(&mut conn)
+        .await
+        .map_err(|_| Error::RecordNotFound)?;
+
+    if let Some(avatar) = &existed_user.avatar {
+        store.delete(avatar).await?;
+    }
+    if let Some(avatar_thumbnail) = &existed_user.avatar_thumbnail {
+        store.delete(avatar_thumbnail).await?;
+    }
+
     delete(users::table.filter(users::id.eq(id)))
         .execute(&mut conn)
         .await
@@ -308,6 +545,119 @@ pub async fn delete_user(
     ))
 }
 
+#[utoipa::path(
+    patch,
+    context_path = "/api",
+    path = "/users/{id}/block",
+    params(
+        ("id" = PublicId, Path, description = "User ID")
+    ),
+    responses(
+        (status = 202, description = "User blocked successfully", body = GenericResponse<String>),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal Server Error"),
+    ),
+    security(("bearerAuth" = [])), // Apply JWT security only here
+    tag = "Users"
+)]
+#[only_role("admin")]
+pub async fn block_user(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(sender): Extension<UserResponse>,
+    Path(id): Path<PublicId>,
+) -> Result<impl IntoResponse> {
+    debug!("block_user: sender {:?}, id {id}", sender);
+
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
+    set_blocked(&db, id.into_uuid(), true).await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(GenericResponse {
+            status: StatusCode::ACCEPTED.to_string(),
+            result: DataResponse::<String> {
+                msg: "User blocked successfully".into(),
+                data: None,
+            },
+        }),
+    ))
+}
+
+#[utoipa::path(
+    patch,
+    context_path = "/api",
+    path = "/users/{id}/unblock",
+    params(
+        ("id" = PublicId, Path, description = "User ID")
+    ),
+    responses(
+        (status = 202, description = "User unblocked successfully", body = GenericResponse<String>),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal Server Error"),
+    ),
+    security(("bearerAuth" = [])), // Apply JWT security only here
+    tag = "Users"
+)]
+#[only_role("admin")]
+pub async fn unblock_user(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(sender): Extension<UserResponse>,
+    Path(id): Path<PublicId>,
+) -> Result<impl IntoResponse> {
+    debug!("unblock_user: sender {:?}, id {id}", sender);
+
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
+    set_blocked(&db, id.into_uuid(), false).await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(GenericResponse {
+            status: StatusCode::ACCEPTED.to_string(),
+            result: DataResponse::<String> {
+                msg: "User unblocked successfully".into(),
+                data: None,
+            },
+        }),
+    ))
+}
+
+/// Re-checks the caller's `blocked` flag against the current DB state, so a banned
+/// account's still-valid JWT can't keep hitting authenticated routes.
+async fn ensure_not_blocked(db: &Database, user_id: Uuid) -> Result<()> {
+    let mut conn = db.get_connection().await;
+
+    let blocked = users::table
+        .find(user_id)
+        .select(users::blocked)
+        .first::<bool>(&mut conn)
+        .await
+        .map_err(|_| Error::RecordNotFound)?;
+
+    if blocked {
+        return Err(Error::UserBlocked);
+    }
+
+    Ok(())
+}
+
+async fn set_blocked(db: &Database, id: Uuid, blocked: bool) -> Result<()> {
+    let mut conn = db.get_connection().await;
+
+    let affected = update(users::table.filter(users::id.eq(id)))
+        .set(users::blocked.eq(blocked))
+        .execute(&mut conn)
+        .await
+        .map_err(|_| Error::RecordNotFound)?;
+
+    if affected == 0 {
+        return Err(Error::RecordNotFound);
+    }
+
+    Ok(())
+}
+
 #[utoipa::path(
     post,
     context_path = "/api",
@@ -325,10 +675,13 @@ pub async fn delete_user(
 pub async fn update_avatar(
     Extension(db): Extension<Arc<Database>>,
     Extension(sender): Extension<UserResponse>,
+    Extension(store): Extension<Arc<dyn AvatarStore>>,
     mut multipart: Multipart,
 ) -> Result<impl IntoResponse> {
     debug!("update_avatar: sender {sender:?}, multipart: {multipart:?}");
 
+    ensure_not_blocked(&db, sender.id.into_uuid()).await?;
+
     let mut updated = false;
     while let Some(field) = multipart
         .next_field()
@@ -350,24 +703,30 @@ pub async fn update_avatar(
             if regex.is_match(content_type) {
                 let mut conn = db.get_connection().await;
                 let mut existed_user: User = users::table
-                    .find(sender.id)
+                    .find(sender.id.into_uuid())
                     .select(User::as_select())
                     .first(&mut conn)
                     .await
                     .map_err(|_| Error::RecordNotFound)?;
 
-                let new_filename = format!("{filename}-{}.{extension}", Uuid::new_v4());
-                existed_user.avatar = Some(new_filename.to_string());
-
-                let mut file = File::create(format!("public/uploads/{new_filename}"))
-                    .await
-                    .map_err(|_| Error::CreateFileFailed)?;
                 let data = field.bytes().await.map_err(|e| Error::Anyhow(e.into()))?;
-                file.write(&data)
-                    .await
-                    .map_err(|e| Error::Anyhow(e.into()))?;
+                let processed = process_avatar(&data)?;
+
+                let basename = format!("{filename}-{}", Uuid::new_v4());
+                let avatar_key = format!("{basename}.png");
+                let thumbnail_key = format!("{basename}-thumb.png");
+
+                let avatar_url = store
+                    .put(&avatar_key, processed.full, "image/png")
+                    .await?;
+                let thumbnail_url = store
+                    .put(&thumbnail_key, processed.thumbnail, "image/png")
+                    .await?;
+
+                existed_user.avatar = Some(avatar_url);
+                existed_user.avatar_thumbnail = Some(thumbnail_url);
 
-                update(users::table)
+                update(users::table.filter(users::id.eq(sender.id.into_uuid())))
                     .set(existed_user)
                     .execute(&mut conn)
                     .await
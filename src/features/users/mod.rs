@@ -0,0 +1,7 @@
+pub mod avatar;
+pub mod dto;
+pub mod handlers;
+pub mod models;
+pub mod password;
+pub mod public_id;
+pub mod storage;
@@ -0,0 +1,38 @@
+use {
+    crate::schema::users,
+    chrono::{
+        DateTime,
+        Utc,
+    },
+    diesel::prelude::*,
+    uuid::Uuid,
+};
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, AsChangeset)]
+#[diesel(table_name = users)]
+pub struct User {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub password: Option<String>,
+    pub role: String,
+    pub avatar: Option<String>,
+    pub avatar_thumbnail: Option<String>,
+    pub blocked: bool,
+    pub invite_token: Option<String>,
+    pub invite_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = users)]
+pub struct NewUser<'a> {
+    pub name: &'a str,
+    pub email: &'a str,
+    pub password: Option<&'a str>,
+    pub role: &'a str,
+    pub avatar: Option<&'a str>,
+    pub invite_token: Option<&'a str>,
+    pub invite_expires_at: Option<DateTime<Utc>>,
+}
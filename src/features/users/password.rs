@@ -0,0 +1,66 @@
+use {
+    crate::enums::errors::Error,
+    argon2::{
+        password_hash::{
+            rand_core::OsRng,
+            PasswordHash,
+            PasswordHasher,
+            PasswordVerifier,
+            SaltString,
+        },
+        Argon2,
+    },
+};
+
+/// Hashes a plaintext password into a PHC-format string, ready to persist in `users.password`.
+pub fn hash_password(password: &str) -> Result<String, Error> {
+    let salt = SaltString::generate(&mut OsRng);
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|_| Error::PasswordHashFailed)
+}
+
+/// Verifies a plaintext password against a stored PHC-format hash, e.g. during login.
+pub fn verify_password(hash: &str, password: &str) -> bool {
+    let Ok(parsed_hash) = PasswordHash::new(hash) else {
+        return false;
+    };
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_then_verify_round_trips() {
+        let hash = hash_password("correct horse battery staple").unwrap();
+
+        assert!(verify_password(&hash, "correct horse battery staple"));
+    }
+
+    #[test]
+    fn verify_rejects_wrong_password() {
+        let hash = hash_password("correct horse battery staple").unwrap();
+
+        assert!(!verify_password(&hash, "wrong password"));
+    }
+
+    #[test]
+    fn verify_rejects_malformed_hash() {
+        assert!(!verify_password("not a phc hash", "anything"));
+    }
+
+    #[test]
+    fn hashing_the_same_password_twice_yields_different_salts() {
+        let first = hash_password("same password").unwrap();
+        let second = hash_password("same password").unwrap();
+
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,132 @@
+use {
+    crate::enums::errors::Error,
+    serde::{
+        de,
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    },
+    sqids::Sqids,
+    std::{
+        fmt,
+        str::FromStr,
+        sync::OnceLock,
+    },
+    utoipa::{
+        openapi::{
+            ObjectBuilder,
+            SchemaType,
+        },
+        PartialSchema,
+        ToSchema,
+    },
+    uuid::Uuid,
+};
+
+static SQIDS: OnceLock<Sqids> = OnceLock::new();
+
+/// Configures the per-deployment alphabet `PublicId` encodes/decodes with. Must be called
+/// once at startup (e.g. from the `AVATAR_PUBLIC_ID_ALPHABET`/config secret) before any
+/// `PublicId` is displayed or parsed — relying on `sqids`'s default alphabet would let
+/// anyone decode a short ID back to the source `Uuid` with the library alone.
+pub fn init(alphabet: &str) {
+    let sqids = Sqids::builder()
+        .alphabet(alphabet.chars().collect())
+        .min_length(8)
+        .build()
+        .expect("invalid sqids alphabet");
+
+    SQIDS.set(sqids).ok();
+}
+
+fn sqids() -> &'static Sqids {
+    SQIDS
+        .get()
+        .expect("public_id::init must be called during startup before PublicId is used")
+}
+
+/// A short, opaque stand-in for a user's internal `Uuid`, so routes like `/users/{id}`
+/// never leak the raw primary key format to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicId(Uuid);
+
+impl PublicId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for PublicId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (high, low) = self.0.as_u64_pair();
+        let encoded = sqids().encode(&[high, low]).unwrap_or_default();
+        write!(f, "{encoded}")
+    }
+}
+
+impl FromStr for PublicId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decoded = sqids().decode(s);
+        let [high, low] = decoded.as_slice() else {
+            return Err(Error::RecordNotFound);
+        };
+
+        Ok(Self(Uuid::from_u64_pair(*high, *low)))
+    }
+}
+
+impl Serialize for PublicId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl PartialSchema for PublicId {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::Schema> {
+        ObjectBuilder::new().schema_type(SchemaType::String).into()
+    }
+}
+
+impl ToSchema for PublicId {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ensure_initialized() {
+        init("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789");
+    }
+
+    #[test]
+    fn encode_then_parse_round_trips() {
+        ensure_initialized();
+        let id = Uuid::new_v4();
+
+        let public_id = PublicId::new(id);
+        let parsed: PublicId = public_id.to_string().parse().unwrap();
+
+        assert_eq!(parsed.into_uuid(), id);
+    }
+
+    #[test]
+    fn parsing_garbage_is_not_a_valid_uuid() {
+        ensure_initialized();
+
+        assert!(matches!("not-a-real-id".parse::<PublicId>(), Err(Error::RecordNotFound)));
+    }
+}
@@ -0,0 +1,102 @@
+use {
+    crate::enums::errors::Error,
+    async_trait::async_trait,
+    tokio::{
+        fs,
+        io::AsyncWriteExt,
+    },
+};
+
+/// Abstraction over where uploaded avatar bytes end up, so `update_avatar` doesn't need to
+/// know whether it's talking to the local disk or an S3-compatible bucket.
+#[async_trait]
+pub trait AvatarStore: Send + Sync {
+    /// Persists `bytes` under `key` and returns the URL/key to save on `User.avatar`.
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<String, Error>;
+    async fn delete(&self, key: &str) -> Result<(), Error>;
+}
+
+pub struct LocalAvatarStore {
+    base_dir: String,
+}
+
+impl LocalAvatarStore {
+    pub fn new(base_dir: impl Into<String>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AvatarStore for LocalAvatarStore {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> Result<String, Error> {
+        let mut file = fs::File::create(format!("{}/{key}", self.base_dir))
+            .await
+            .map_err(|_| Error::CreateFileFailed)?;
+        file.write_all(&bytes)
+            .await
+            .map_err(|e| Error::Anyhow(e.into()))?;
+
+        Ok(key.to_string())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        fs::remove_file(format!("{}/{key}", self.base_dir))
+            .await
+            .or_else(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => Ok(()),
+                _ => Err(e),
+            })
+            .map_err(|e| Error::Anyhow(e.into()))
+    }
+}
+
+pub struct S3AvatarStore {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    public_base_url: String,
+}
+
+impl S3AvatarStore {
+    pub fn new(client: aws_sdk_s3::Client, bucket: impl Into<String>, public_base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            bucket: bucket.into(),
+            public_base_url: public_base_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AvatarStore for S3AvatarStore {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<String, Error> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(bytes.into())
+            .send()
+            .await
+            .map_err(|e| Error::Anyhow(e.into()))?;
+
+        Ok(format!("{}/{key}", self.public_base_url))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        // `put` hands back `{public_base_url}/{key}`, which is what ends up on `User.avatar`.
+        let prefix = format!("{}/", self.public_base_url);
+        let key = key.strip_prefix(&prefix).unwrap_or(key);
+
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| Error::Anyhow(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+diesel::table! {
+    users (id) {
+        id -> Uuid,
+        name -> Varchar,
+        email -> Varchar,
+        password -> Nullable<Varchar>,
+        role -> Varchar,
+        avatar -> Nullable<Varchar>,
+        avatar_thumbnail -> Nullable<Varchar>,
+        blocked -> Bool,
+        invite_token -> Nullable<Varchar>,
+        invite_expires_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}